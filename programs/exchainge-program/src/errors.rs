@@ -2,12 +2,16 @@ use anchor_lang::prelude::*;
 
 #[error_code]
 pub enum ErrorCode {
+    // Retired: replaced by InternalKeyEmpty / InternalKeyTooLong. Never raised;
+    // kept only so later variants keep their positional error codes.
     #[msg("Internal key is empty or too long (max 64 chars)")]
     InvalidInternalKey,
 
     #[msg("Dataset hash is invalid")]
     InvalidDatasetHash,
 
+    // Retired: replaced by FileKeyEmpty / FileKeyTooLong. Never raised;
+    // kept only so later variants keep their positional error codes.
     #[msg("File key is empty or too long (max 100 chars)")]
     InvalidFileKey,
 
@@ -22,4 +26,37 @@ pub enum ErrorCode {
 
     #[msg("Unauthorized: only owner can update or close")]
     Unauthorized,
+
+    #[msg("Registry has no pending commitment to reveal")]
+    NoPendingCommitment,
+
+    #[msg("Revealed hash and salt do not match the commitment")]
+    CommitmentMismatch,
+
+    #[msg("Registry commitment has not been revealed yet")]
+    CommitmentNotRevealed,
+
+    #[msg("Only trustless registrations can be re-attested")]
+    NotTrustlessRegistry,
+
+    #[msg("File key must not be empty")]
+    FileKeyEmpty,
+
+    #[msg("File key is too long (max 100 chars)")]
+    FileKeyTooLong,
+
+    #[msg("Internal key must not be empty")]
+    InternalKeyEmpty,
+
+    #[msg("Internal key is too long (max 64 chars)")]
+    InternalKeyTooLong,
+
+    #[msg("Registry revision counter overflowed")]
+    RevisionOverflow,
+
+    #[msg("Registry uses an outdated account layout")]
+    RegistryNotMigrated,
+
+    #[msg("Registry already uses the current account layout")]
+    RegistryAlreadyMigrated,
 }
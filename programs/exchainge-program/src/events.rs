@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use crate::state::RegistrationMethod;
 
 /// Event emitted when a dataset is registered with on-chain hash computation (trustless method)
 #[event]
@@ -36,3 +37,47 @@ pub struct RegistryClosed {
     pub owner: Pubkey,
     pub timestamp: i64,
 }
+
+/// Event emitted when a hash commitment is recorded (commit-reveal method)
+#[event]
+pub struct HashCommitted {
+    pub registry_address: Pubkey,
+    pub commitment: [u8; 32],
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a committed hash is revealed
+#[event]
+pub struct HashRevealed {
+    pub registry_address: Pubkey,
+    pub internal_key: String,
+    pub dataset_hash: [u8; 32],
+    pub owner: Pubkey,
+    pub committed_at: i64,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a trustless registration is re-attested after a file change
+#[event]
+pub struct DatasetReattested {
+    pub registry_address: Pubkey,
+    pub dataset_id: u64,
+    pub revision: u32,
+    pub previous_hash: [u8; 32],
+    pub new_hash: [u8; 32],
+    pub file_size: u64,
+    pub file_key: String,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
+
+/// Event emitted when a pre-versioning registry is upgraded to the current layout
+#[event]
+pub struct RegistryMigrated {
+    pub registry_address: Pubkey,
+    pub layout_version: u8,
+    pub method: RegistrationMethod,
+    pub owner: Pubkey,
+    pub timestamp: i64,
+}
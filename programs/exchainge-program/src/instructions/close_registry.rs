@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+
+/// Close a registry and reclaim rent (owner only)
+pub fn process_close_registry(ctx: Context<CloseRegistry>) -> Result<()> {
+    let clock = Clock::get()?;
+
+    // Emit event
+    emit!(RegistryClosed {
+        registry_address: ctx.accounts.registry.key(),
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Registry closed, rent reclaimed");
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CloseRegistry<'info> {
+    #[account(
+        mut,
+        close = owner,
+        constraint = registry.owner == owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub registry: Account<'info, DataRegistry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::*;
+
+/// Commit to sha256(dataset_hash || salt) without revealing the hash
+/// Timestamps data existence; the registry stays pending until revealed
+pub fn process_commit_hash(
+    ctx: Context<CommitHash>,
+    commitment: [u8; 32],
+) -> Result<()> {
+    // Store in registry account
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    registry.owner = ctx.accounts.owner.key();
+    registry.internal_key = String::new();
+    registry.dataset_hash = [0u8; 32];
+    registry.method = RegistrationMethod::CommitReveal;
+    registry.dataset_id = None;
+    registry.file_size = None;
+    registry.file_key = None;
+    registry.created_at = clock.unix_timestamp;
+    registry.commitment = Some(commitment);
+    registry.revision = 0;
+    registry.previous_hash = None;
+    registry.bump = 0;
+    registry.layout_version = DataRegistry::LAYOUT_VERSION;
+
+    // Emit event
+    emit!(HashCommitted {
+        registry_address: ctx.accounts.registry.key(),
+        commitment,
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Hash committed: Commitment={:?}", commitment);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct CommitHash<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = DataRegistry::LEN
+    )]
+    pub registry: Account<'info, DataRegistry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
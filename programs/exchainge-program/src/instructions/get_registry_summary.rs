@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+
+/// Return a compact registry summary via return data
+/// Lets CLIs and other programs read the entry without decoding the full account
+pub fn process_get_registry_summary(ctx: Context<GetRegistrySummary>) -> Result<RegistrySummary> {
+    let registry = &ctx.accounts.registry;
+
+    Ok(RegistrySummary {
+        owner: registry.owner,
+        dataset_hash: registry.dataset_hash,
+        created_at: registry.created_at,
+        method: registry.method,
+        pending: registry.commitment.is_some(),
+    })
+}
+
+#[derive(Accounts)]
+pub struct GetRegistrySummary<'info> {
+    #[account(
+        constraint = registry.layout_version == DataRegistry::LAYOUT_VERSION @ ErrorCode::RegistryNotMigrated
+    )]
+    pub registry: Account<'info, DataRegistry>,
+}
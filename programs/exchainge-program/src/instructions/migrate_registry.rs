@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+
+/// Upgrade a pre-versioning registry to the current layout (owner only)
+/// Grows the account to DataRegistry::LEN, topping up rent from the owner,
+/// then fills in the appended fields (method inferred from dataset_id)
+pub fn process_migrate_registry(ctx: Context<MigrateRegistry>) -> Result<()> {
+    let registry_info = ctx.accounts.registry.to_account_info();
+    let clock = Clock::get()?;
+
+    // Old accounts may be too small to deserialize, so check the raw bytes first
+    {
+        let data = registry_info.try_borrow_data()?;
+        require!(
+            data.len() >= 8 + 32 && data[..8] == *DataRegistry::DISCRIMINATOR,
+            anchor_lang::error::ErrorCode::AccountDiscriminatorMismatch
+        );
+        require!(
+            data[8..40] == ctx.accounts.owner.key().to_bytes(),
+            ErrorCode::Unauthorized
+        );
+    }
+
+    // Grow the account, funding the extra rent from the owner
+    if registry_info.data_len() < DataRegistry::LEN {
+        let required = Rent::get()?.minimum_balance(DataRegistry::LEN);
+        let top_up = required.saturating_sub(registry_info.lamports());
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    system_program::Transfer {
+                        from: ctx.accounts.owner.to_account_info(),
+                        to: registry_info.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        registry_info.resize(DataRegistry::LEN)?;
+    }
+
+    // Appended fields now read from zeroed bytes
+    let mut data = registry_info.try_borrow_mut_data()?;
+    let mut registry = DataRegistry::try_deserialize(&mut &data[..])?;
+    require!(registry.layout_version == 0, ErrorCode::RegistryAlreadyMigrated);
+
+    registry.upgrade_legacy();
+    registry.try_serialize(&mut &mut data[..])?;
+
+    // Emit event
+    emit!(RegistryMigrated {
+        registry_address: registry_info.key(),
+        layout_version: registry.layout_version,
+        method: registry.method,
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Registry migrated: Layout={}, Method={:?}",
+        registry.layout_version,
+        registry.method
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateRegistry<'info> {
+    /// CHECK: pre-versioning accounts can be too small to deserialize as
+    /// DataRegistry; discriminator and stored owner are checked in the handler
+    #[account(mut, owner = crate::ID)]
+    pub registry: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,24 @@
+pub mod validation;
+pub mod register_dataset;
+pub mod register_hash;
+pub mod update_hash;
+pub mod view_hash;
+pub mod close_registry;
+pub mod commit_hash;
+pub mod reveal_registration;
+pub mod validate_registration_params;
+pub mod get_registry_summary;
+pub mod reattest_dataset;
+pub mod migrate_registry;
+
+pub use register_dataset::*;
+pub use register_hash::*;
+pub use update_hash::*;
+pub use view_hash::*;
+pub use close_registry::*;
+pub use commit_hash::*;
+pub use reveal_registration::*;
+pub use validate_registration_params::*;
+pub use get_registry_summary::*;
+pub use reattest_dataset::*;
+pub use migrate_registry::*;
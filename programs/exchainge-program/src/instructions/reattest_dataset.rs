@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use super::validation::{validate_file_key, validate_file_size, derive_dataset_hash};
+
+/// Recompute the trustless hash after the file was re-uploaded (owner only)
+/// Keeps the replaced hash in previous_hash and bumps the revision counter
+pub fn process_reattest_dataset(
+    ctx: Context<ReattestDataset>,
+    file_size: u64,
+    file_key: String,
+) -> Result<()> {
+    // Validate inputs
+    validate_file_key(&file_key)?;
+    validate_file_size(file_size)?;
+
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    // Only trustless registrations store the dataset_id the hash is derived from
+    let dataset_id = registry.dataset_id.ok_or(ErrorCode::NotTrustlessRegistry)?;
+
+    // Recompute SHA-256 hash on-chain with the original dataset_id
+    let derived_hash = derive_dataset_hash(&file_key, dataset_id, file_size);
+    let previous_hash = registry.dataset_hash;
+    let revision = registry
+        .revision
+        .checked_add(1)
+        .ok_or(ErrorCode::RevisionOverflow)?;
+
+    registry.previous_hash = Some(previous_hash);
+    registry.dataset_hash = derived_hash;
+    registry.file_size = Some(file_size);
+    registry.file_key = Some(file_key.clone());
+    registry.revision = revision;
+
+    // Emit event
+    emit!(DatasetReattested {
+        registry_address: ctx.accounts.registry.key(),
+        dataset_id,
+        revision,
+        previous_hash,
+        new_hash: derived_hash,
+        file_size,
+        file_key,
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Dataset re-attested: ID={}, Revision={}, Hash={:?}",
+        dataset_id,
+        revision,
+        derived_hash
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ReattestDataset<'info> {
+    #[account(
+        mut,
+        constraint = registry.owner == owner.key() @ ErrorCode::Unauthorized,
+        constraint = registry.layout_version == DataRegistry::LAYOUT_VERSION @ ErrorCode::RegistryNotMigrated
+    )]
+    pub registry: Account<'info, DataRegistry>,
+
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::*;
+use super::validation::{validate_file_key, validate_file_size, derive_dataset_hash};
+
+/// Register dataset with on-chain SHA-256 hash computation
+/// More secure and trustless - hash is computed on-chain from inputs
+pub fn process_register_dataset(
+    ctx: Context<RegisterDataset>,
+    dataset_id: u64,
+    file_size: u64,
+    file_key: String,
+) -> Result<()> {
+    // Validate inputs
+    validate_file_key(&file_key)?;
+    validate_file_size(file_size)?;
+
+    // Compute SHA-256 hash on-chain: sha256(file_key:dataset_id:file_size)
+    let derived_hash = derive_dataset_hash(&file_key, dataset_id, file_size);
+
+    // Store in registry account
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    registry.owner = ctx.accounts.owner.key();
+    registry.internal_key = format!("dataset_{}", dataset_id);
+    registry.dataset_hash = derived_hash;
+    registry.method = RegistrationMethod::Trustless;
+    registry.dataset_id = Some(dataset_id);
+    registry.file_size = Some(file_size);
+    registry.file_key = Some(file_key.clone());
+    registry.created_at = clock.unix_timestamp;
+    registry.commitment = None;
+    registry.revision = 0;
+    registry.previous_hash = None;
+    registry.bump = 0;
+    registry.layout_version = DataRegistry::LAYOUT_VERSION;
+
+    // Emit event
+    emit!(DatasetRegistered {
+        dataset_id,
+        file_size,
+        file_key,
+        derived_hash,
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Dataset registered (trustless): ID={}, Hash={:?}",
+        dataset_id,
+        derived_hash
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterDataset<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = DataRegistry::LEN
+    )]
+    pub registry: Account<'info, DataRegistry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::events::*;
+use super::validation::validate_internal_key;
+
+/// Register with pre-computed hash (cheaper, requires trust in client)
+pub fn process_register_hash(
+    ctx: Context<RegisterHash>,
+    internal_key: String,
+    dataset_hash: [u8; 32],
+) -> Result<()> {
+    // Validate inputs
+    validate_internal_key(&internal_key)?;
+
+    // Store in registry account
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    registry.owner = ctx.accounts.owner.key();
+    registry.internal_key = internal_key.clone();
+    registry.dataset_hash = dataset_hash;
+    registry.method = RegistrationMethod::Precomputed;
+    registry.dataset_id = None;
+    registry.file_size = None;
+    registry.file_key = None;
+    registry.created_at = clock.unix_timestamp;
+    registry.commitment = None;
+    registry.revision = 0;
+    registry.previous_hash = None;
+    registry.bump = 0;
+    registry.layout_version = DataRegistry::LAYOUT_VERSION;
+
+    // Emit event
+    emit!(HashRegistered {
+        internal_key: internal_key.clone(),
+        dataset_hash,
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Hash registered (pre-computed): Key={}, Hash={:?}",
+        internal_key,
+        dataset_hash
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RegisterHash<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = DataRegistry::LEN
+    )]
+    pub registry: Account<'info, DataRegistry>,
+
+    #[account(mut)]
+    pub owner: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+use sha2::{Sha256, Digest};
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+use super::validation::validate_internal_key;
+
+/// Reveal a committed hash (owner only)
+/// Validates sha256(dataset_hash || salt) == commitment and keeps the commit timestamp
+pub fn process_reveal_registration(
+    ctx: Context<RevealRegistration>,
+    internal_key: String,
+    dataset_hash: [u8; 32],
+    salt: [u8; 32],
+) -> Result<()> {
+    // Validate inputs
+    validate_internal_key(&internal_key)?;
+
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    let commitment = registry.commitment.ok_or(ErrorCode::NoPendingCommitment)?;
+
+    // Recompute commitment on-chain: sha256(dataset_hash || salt)
+    let mut hasher = Sha256::new();
+    hasher.update(dataset_hash);
+    hasher.update(salt);
+    let computed: [u8; 32] = hasher.finalize().into();
+    require!(computed == commitment, ErrorCode::CommitmentMismatch);
+
+    // created_at is left untouched so the registry keeps the commit timestamp
+    registry.internal_key = internal_key.clone();
+    registry.dataset_hash = dataset_hash;
+    registry.commitment = None;
+
+    // Emit event
+    emit!(HashRevealed {
+        registry_address: ctx.accounts.registry.key(),
+        internal_key: internal_key.clone(),
+        dataset_hash,
+        owner: ctx.accounts.owner.key(),
+        committed_at: ctx.accounts.registry.created_at,
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!(
+        "Hash revealed: Key={}, Hash={:?}",
+        internal_key,
+        dataset_hash
+    );
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RevealRegistration<'info> {
+    #[account(
+        mut,
+        constraint = registry.owner == owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub registry: Account<'info, DataRegistry>,
+
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::ErrorCode;
+use crate::events::*;
+
+/// Update the hash for an existing registry entry (owner only)
+pub fn process_update_hash(
+    ctx: Context<UpdateHash>,
+    new_dataset_hash: [u8; 32],
+) -> Result<()> {
+    let registry = &mut ctx.accounts.registry;
+    let clock = Clock::get()?;
+
+    require!(registry.commitment.is_none(), ErrorCode::CommitmentNotRevealed);
+
+    registry.dataset_hash = new_dataset_hash;
+
+    // Emit event
+    emit!(RegistryUpdated {
+        registry_address: ctx.accounts.registry.key(),
+        new_hash: new_dataset_hash,
+        owner: ctx.accounts.owner.key(),
+        timestamp: clock.unix_timestamp,
+    });
+
+    msg!("Registry updated: {:?}", new_dataset_hash);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateHash<'info> {
+    #[account(
+        mut,
+        constraint = registry.owner == owner.key() @ ErrorCode::Unauthorized
+    )]
+    pub registry: Account<'info, DataRegistry>,
+
+    pub owner: Signer<'info>,
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use super::validation::{validate_file_key, validate_file_size, derive_dataset_hash};
+
+/// Dry-run the register_dataset checks without creating an account
+/// Reports every failing check at once and returns the hash that would be stored
+pub fn process_validate_registration_params(
+    _ctx: Context<ValidateRegistrationParams>,
+    dataset_id: u64,
+    file_size: u64,
+    file_key: String,
+) -> Result<RegistrationValidation> {
+    let file_key_valid = validate_file_key(&file_key).is_ok();
+    let file_size_valid = validate_file_size(file_size).is_ok();
+
+    let is_valid = file_key_valid && file_size_valid;
+
+    let result = RegistrationValidation {
+        is_valid,
+        file_key_valid,
+        file_size_valid,
+        derived_hash: is_valid.then(|| derive_dataset_hash(&file_key, dataset_id, file_size)),
+    };
+
+    msg!(
+        "Registration params validated: ID={}, Valid={}",
+        dataset_id,
+        result.is_valid
+    );
+
+    Ok(result)
+}
+
+#[derive(Accounts)]
+pub struct ValidateRegistrationParams {}
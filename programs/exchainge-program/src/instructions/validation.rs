@@ -0,0 +1,31 @@
+use anchor_lang::prelude::*;
+use sha2::{Sha256, Digest};
+use crate::errors::ErrorCode;
+
+/// File key must be non-empty and at most 100 chars
+pub fn validate_file_key(file_key: &str) -> Result<()> {
+    require!(!file_key.is_empty(), ErrorCode::FileKeyEmpty);
+    require!(file_key.len() <= 100, ErrorCode::FileKeyTooLong);
+    Ok(())
+}
+
+/// File size must be greater than 0
+pub fn validate_file_size(file_size: u64) -> Result<()> {
+    require!(file_size > 0, ErrorCode::InvalidFileSize);
+    Ok(())
+}
+
+/// Internal key must be non-empty and at most 64 chars
+pub fn validate_internal_key(internal_key: &str) -> Result<()> {
+    require!(!internal_key.is_empty(), ErrorCode::InternalKeyEmpty);
+    require!(internal_key.len() <= 64, ErrorCode::InternalKeyTooLong);
+    Ok(())
+}
+
+/// Trustless dataset hash: sha256(file_key:dataset_id:file_size)
+pub fn derive_dataset_hash(file_key: &str, dataset_id: u64, file_size: u64) -> [u8; 32] {
+    let hash_input = format!("{}:{}:{}", file_key, dataset_id, file_size);
+    let mut hasher = Sha256::new();
+    hasher.update(hash_input.as_bytes());
+    hasher.finalize().into()
+}
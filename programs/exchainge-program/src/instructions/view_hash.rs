@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+/// View/fetch registry data by registry account address
+/// This is a read instruction - just fetches account data
+/// In practice, clients can also directly fetch the account using Connection.getAccountInfo()
+pub fn process_view_hash(ctx: Context<ViewHash>) -> Result<()> {
+    let registry = &ctx.accounts.registry;
+
+    msg!("Registry data:");
+    msg!("  Owner: {}", registry.owner);
+    msg!("  Internal Key: {}", registry.internal_key);
+    msg!("  Dataset Hash: {:?}", registry.dataset_hash);
+    msg!("  Layout Version: {}", registry.layout_version);
+    msg!("  Method: {:?}", registry.method);
+    msg!("  Dataset ID: {:?}", registry.dataset_id);
+    msg!("  File Size: {:?}", registry.file_size);
+    msg!("  File Key: {:?}", registry.file_key);
+    msg!("  Created At: {}", registry.created_at);
+    msg!("  Commitment: {:?}", registry.commitment);
+    msg!("  Revision: {}", registry.revision);
+    msg!("  Previous Hash: {:?}", registry.previous_hash);
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct ViewHash<'info> {
+    pub registry: Account<'info, DataRegistry>,
+}
@@ -1,4 +1,9 @@
 #![allow(unexpected_cfgs)]
+// Anchor 0.31's #[program] emits its IDL handler (__private::__idl) at the
+// crate root, and that handler calls the deprecated AccountInfo::realloc.
+// An allow on the program module does not reach it, so it has to live here.
+// Remove once Anchor switches the generated code to AccountInfo::resize.
+#![allow(deprecated)]
 
 use anchor_lang::prelude::*;
 
@@ -18,6 +23,7 @@ declare_id!("2yvGQ26fz2mvPnxDa2wcTf5Y88hr9sTSJpiZdFqMyQ4L");
 /// Supports two methods for dataset registration:
 /// 1. Trustless (on-chain hash): register_dataset - computes SHA-256 on-chain
 /// 2. Cheaper (pre-computed): register_hash - stores client-provided hash
+/// 3. Commit-reveal: commit_hash now, reveal_registration later
 #[program]
 pub mod exchainge_program {
     use super::*;
@@ -78,4 +84,88 @@ pub mod exchainge_program {
     pub fn close_registry(ctx: Context<CloseRegistry>) -> Result<()> {
         instructions::process_close_registry(ctx)
     }
+
+    /// Commit to a hash without revealing it (commit-reveal method)
+    ///
+    /// Timestamps data existence before the listing is revealed
+    ///
+    /// # Arguments
+    /// * `commitment` - SHA256(dataset_hash || salt) (32 bytes)
+    pub fn commit_hash(
+        ctx: Context<CommitHash>,
+        commitment: [u8; 32],
+    ) -> Result<()> {
+        instructions::process_commit_hash(ctx, commitment)
+    }
+
+    /// Reveal a previously committed hash (owner only)
+    ///
+    /// Verifies SHA256(dataset_hash || salt) matches the stored commitment.
+    /// The original commit timestamp is preserved as `created_at`.
+    ///
+    /// # Arguments
+    /// * `internal_key` - Unique identifier for this registry entry
+    /// * `dataset_hash` - The committed dataset hash (32 bytes)
+    /// * `salt` - Salt used when building the commitment (32 bytes)
+    pub fn reveal_registration(
+        ctx: Context<RevealRegistration>,
+        internal_key: String,
+        dataset_hash: [u8; 32],
+        salt: [u8; 32],
+    ) -> Result<()> {
+        instructions::process_reveal_registration(ctx, internal_key, dataset_hash, salt)
+    }
+
+    /// Dry-run register_dataset validation without writing state (read-only)
+    ///
+    /// Returns a RegistrationValidation via return data so clients can
+    /// pre-flight parameters before paying for a failing transaction.
+    ///
+    /// # Arguments
+    /// * `dataset_id` - Unique dataset identifier
+    /// * `file_size` - File size in bytes
+    /// * `file_key` - File key/identifier used in hash computation
+    pub fn validate_registration_params(
+        ctx: Context<ValidateRegistrationParams>,
+        dataset_id: u64,
+        file_size: u64,
+        file_key: String,
+    ) -> Result<RegistrationValidation> {
+        instructions::process_validate_registration_params(ctx, dataset_id, file_size, file_key)
+    }
+
+    /// Get a compact registry summary (read-only)
+    ///
+    /// Returns (owner, hash, created_at, method, pending) as a RegistrySummary
+    /// via return data, so consumers don't infer the method from Option fields.
+    /// `pending` is set for unrevealed commitments, whose hash is all zeros.
+    pub fn get_registry_summary(ctx: Context<GetRegistrySummary>) -> Result<RegistrySummary> {
+        instructions::process_get_registry_summary(ctx)
+    }
+
+    /// Re-attest a trustless registration after the file changed (owner only)
+    ///
+    /// Recomputes hash = SHA256(file_key:dataset_id:file_size) with the
+    /// original dataset_id, keeps the old hash in `previous_hash` and bumps
+    /// `revision`.
+    ///
+    /// # Arguments
+    /// * `file_size` - New file size in bytes
+    /// * `file_key` - New file key/identifier used in hash computation
+    pub fn reattest_dataset(
+        ctx: Context<ReattestDataset>,
+        file_size: u64,
+        file_key: String,
+    ) -> Result<()> {
+        instructions::process_reattest_dataset(ctx, file_size, file_key)
+    }
+
+    /// Upgrade a pre-versioning registry to the current layout (owner only)
+    ///
+    /// Reallocates the account to DataRegistry::LEN (owner pays the extra
+    /// rent) and fills in the appended fields. Required before
+    /// get_registry_summary or reattest_dataset on older accounts.
+    pub fn migrate_registry(ctx: Context<MigrateRegistry>) -> Result<()> {
+        instructions::process_migrate_registry(ctx)
+    }
 }
@@ -22,17 +22,229 @@ pub struct DataRegistry {
     /// Optional: File key used for hash derivation (if using trustless method)
     pub file_key: Option<String>,
 
-    /// Timestamp when registered
+    /// Timestamp when registered (commit time for commit-reveal registrations)
     pub created_at: i64,
 
     /// PDA bump seed (if using PDA)
     pub bump: u8,
+
+    // Fields below were appended after the first deployment. Accounts created
+    // before that read them from zeroed padding, so layout_version is 0 there.
+
+    /// Account layout version (0 = pre-versioning account, see LAYOUT_VERSION)
+    pub layout_version: u8,
+
+    /// How this entry was registered (only meaningful when layout_version > 0)
+    pub method: RegistrationMethod,
+
+    /// Optional: Pending commitment sha256(hash || salt) awaiting reveal
+    pub commitment: Option<[u8; 32]>,
+
+    /// Number of times the trustless hash was re-attested
+    pub revision: u32,
+
+    /// Optional: Hash replaced by the latest re-attestation
+    pub previous_hash: Option<[u8; 32]>,
 }
 
 impl DataRegistry {
-    /// Calculate space needed for account
+    /// Current account layout version
+    pub const LAYOUT_VERSION: u8 = 1;
+
+    /// Space allocated for accounts created before layout versioning
     /// 8 (discriminator) + 32 (owner) + 4+64 (internal_key) + 32 (hash)
     /// + 1+8 (Option<dataset_id>) + 1+8 (Option<file_size>)
     /// + 1+4+100 (Option<file_key>) + 8 (created_at) + 1 (bump)
-    pub const LEN: usize = 8 + 32 + 68 + 32 + 9 + 9 + 105 + 8 + 1;
+    pub const LEGACY_LEN: usize = 8 + 32 + 68 + 32 + 9 + 9 + 105 + 8 + 1;
+
+    /// Calculate space needed for account
+    /// LEGACY_LEN + 1 (layout_version) + 1 (method) + 1+32 (Option<commitment>)
+    /// + 4 (revision) + 1+32 (Option<previous_hash>)
+    pub const LEN: usize = Self::LEGACY_LEN + 1 + 1 + 33 + 4 + 33;
+
+    /// Fill in the appended fields of a pre-versioning account
+    /// Only register_dataset stored a dataset_id before versioning, so it
+    /// identifies trustless entries; commit-reveal did not exist yet
+    pub fn upgrade_legacy(&mut self) {
+        self.method = if self.dataset_id.is_some() {
+            RegistrationMethod::Trustless
+        } else {
+            RegistrationMethod::Precomputed
+        };
+        self.commitment = None;
+        self.revision = 0;
+        self.previous_hash = None;
+        self.layout_version = Self::LAYOUT_VERSION;
+    }
+}
+
+/// Registration method used to create a registry entry
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegistrationMethod {
+    /// register_dataset - hash computed on-chain
+    Trustless,
+    /// register_hash - client-provided hash
+    Precomputed,
+    /// commit_hash + reveal_registration - client-provided hash, committed first
+    CommitReveal,
+}
+
+/// Compact registry view, returned via return data by get_registry_summary
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RegistrySummary {
+    pub owner: Pubkey,
+    /// All zeros while `pending` is true
+    pub dataset_hash: [u8; 32],
+    pub created_at: i64,
+    pub method: RegistrationMethod,
+    /// Commitment recorded but not yet revealed
+    pub pending: bool,
+}
+
+/// Result of a register_dataset dry run, returned via return data
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct RegistrationValidation {
+    /// True when every check passes and register_dataset would succeed
+    pub is_valid: bool,
+
+    /// File key is non-empty and at most 100 chars
+    pub file_key_valid: bool,
+
+    /// File size is greater than 0
+    pub file_size_valid: bool,
+
+    /// Hash register_dataset would store: SHA256(file_key:dataset_id:file_size)
+    /// None unless every check passes
+    pub derived_hash: Option<[u8; 32]>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// DataRegistry as serialized before layout versioning
+    #[derive(AnchorSerialize)]
+    struct LegacyDataRegistry {
+        owner: Pubkey,
+        internal_key: String,
+        dataset_hash: [u8; 32],
+        dataset_id: Option<u64>,
+        file_size: Option<u64>,
+        file_key: Option<String>,
+        created_at: i64,
+        bump: u8,
+    }
+
+    /// Build account data the way the pre-versioning program wrote it
+    fn legacy_account_data(legacy: &LegacyDataRegistry) -> Vec<u8> {
+        let mut data = DataRegistry::DISCRIMINATOR.to_vec();
+        legacy.serialize(&mut data).unwrap();
+        data.resize(DataRegistry::LEGACY_LEN, 0);
+        data
+    }
+
+    #[test]
+    fn legacy_trustless_account_keeps_field_offsets() {
+        let owner = Pubkey::new_unique();
+        let data = legacy_account_data(&LegacyDataRegistry {
+            owner,
+            internal_key: "dataset_42".to_string(),
+            dataset_hash: [7u8; 32],
+            dataset_id: Some(42),
+            file_size: Some(1024),
+            file_key: Some("file-key".to_string()),
+            created_at: 1_700_000_000,
+            bump: 0,
+        });
+
+        let registry = DataRegistry::try_deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(registry.owner, owner);
+        assert_eq!(registry.internal_key, "dataset_42");
+        assert_eq!(registry.dataset_hash, [7u8; 32]);
+        assert_eq!(registry.dataset_id, Some(42));
+        assert_eq!(registry.file_size, Some(1024));
+        assert_eq!(registry.file_key.as_deref(), Some("file-key"));
+        assert_eq!(registry.created_at, 1_700_000_000);
+        assert_eq!(registry.layout_version, 0);
+        assert_eq!(registry.commitment, None);
+        assert_eq!(registry.revision, 0);
+        assert_eq!(registry.previous_hash, None);
+    }
+
+    #[test]
+    fn legacy_precomputed_account_keeps_field_offsets() {
+        let data = legacy_account_data(&LegacyDataRegistry {
+            owner: Pubkey::new_unique(),
+            internal_key: "precomputed-key".to_string(),
+            dataset_hash: [9u8; 32],
+            dataset_id: None,
+            file_size: None,
+            file_key: None,
+            created_at: 1_700_000_000,
+            bump: 0,
+        });
+
+        let registry = DataRegistry::try_deserialize(&mut data.as_slice()).unwrap();
+
+        assert_eq!(registry.internal_key, "precomputed-key");
+        assert_eq!(registry.dataset_hash, [9u8; 32]);
+        assert_eq!(registry.dataset_id, None);
+        assert_eq!(registry.file_key, None);
+        assert_eq!(registry.created_at, 1_700_000_000);
+        assert_eq!(registry.layout_version, 0);
+    }
+    #[test]
+    fn legacy_account_with_long_file_key_needs_resize() {
+        let mut data = legacy_account_data(&LegacyDataRegistry {
+            owner: Pubkey::new_unique(),
+            internal_key: format!("dataset_{}", u64::MAX),
+            dataset_hash: [3u8; 32],
+            dataset_id: Some(u64::MAX),
+            file_size: Some(1),
+            file_key: Some("k".repeat(100)),
+            created_at: 1_700_000_000,
+            bump: 0,
+        });
+
+        let mut registry = DataRegistry::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(registry.layout_version, 0);
+        assert_eq!(registry.file_key, Some("k".repeat(100)));
+
+        // Populated appended fields no longer fit in the legacy allocation
+        registry.upgrade_legacy();
+        registry.revision = 1;
+        registry.previous_hash = Some([3u8; 32]);
+        assert!(registry.try_serialize(&mut data.as_mut_slice()).is_err());
+
+        // migrate_registry zero-extends the account to LEN first
+        data.resize(DataRegistry::LEN, 0);
+        registry.try_serialize(&mut data.as_mut_slice()).unwrap();
+
+        let migrated = DataRegistry::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(migrated.layout_version, DataRegistry::LAYOUT_VERSION);
+        assert_eq!(migrated.method, RegistrationMethod::Trustless);
+        assert_eq!(migrated.previous_hash, Some([3u8; 32]));
+        assert_eq!(migrated.file_key, Some("k".repeat(100)));
+    }
+
+    #[test]
+    fn upgrade_legacy_infers_precomputed_without_dataset_id() {
+        let data = legacy_account_data(&LegacyDataRegistry {
+            owner: Pubkey::new_unique(),
+            internal_key: "precomputed-key".to_string(),
+            dataset_hash: [9u8; 32],
+            dataset_id: None,
+            file_size: None,
+            file_key: None,
+            created_at: 1_700_000_000,
+            bump: 0,
+        });
+
+        let mut registry = DataRegistry::try_deserialize(&mut data.as_slice()).unwrap();
+        registry.upgrade_legacy();
+
+        assert_eq!(registry.method, RegistrationMethod::Precomputed);
+        assert_eq!(registry.layout_version, DataRegistry::LAYOUT_VERSION);
+    }
 }
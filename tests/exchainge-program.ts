@@ -61,6 +61,8 @@ describe("exchainge-program", () => {
       expect(registryAccount.datasetId.toNumber()).to.equal(datasetId);
       expect(registryAccount.fileSize.toNumber()).to.equal(fileSize);
       expect(registryAccount.fileKey).to.equal(fileKey);
+      expect(registryAccount.method).to.deep.equal({ trustless: {} });
+      expect(registryAccount.layoutVersion).to.equal(1);
 
       // Critical: Verify on-chain computed hash matches expected
       expect(Buffer.from(registryAccount.datasetHash).toString('hex')).to.equal(
@@ -90,11 +92,36 @@ describe("exchainge-program", () => {
 
         expect.fail("Should have thrown error for empty file_key");
       } catch (err) {
-        expect(err.toString()).to.include("InvalidFileKey");
+        expect(err.toString()).to.include("FileKeyEmpty");
         console.log("✅ Correctly rejected empty file_key");
       }
     });
 
+    it("Rejects file_key longer than 100 chars", async () => {
+      const registryKeypair = anchor.web3.Keypair.generate();
+
+      try {
+        await program.methods
+          .registerDataset(
+            new anchor.BN(123),
+            new anchor.BN(1000),
+            "k".repeat(101) // Too-long file_key
+          )
+          .accounts({
+            registry: registryKeypair.publicKey,
+            owner: owner.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .signers([registryKeypair])
+          .rpc();
+
+        expect.fail("Should have thrown error for too-long file_key");
+      } catch (err) {
+        expect(err.toString()).to.include("FileKeyTooLong");
+        console.log("✅ Correctly rejected too-long file_key");
+      }
+    });
+
     it("Rejects zero file_size", async () => {
       const registryKeypair = anchor.web3.Keypair.generate();
 
@@ -156,6 +183,7 @@ describe("exchainge-program", () => {
       expect(registryAccount.datasetId).to.be.null;
       expect(registryAccount.fileSize).to.be.null;
       expect(registryAccount.fileKey).to.be.null;
+      expect(registryAccount.method).to.deep.equal({ precomputed: {} });
 
       console.log("✅ Pre-computed hash registration successful");
     });
@@ -177,7 +205,7 @@ describe("exchainge-program", () => {
 
         expect.fail("Should have thrown error for empty internal_key");
       } catch (err) {
-        expect(err.toString()).to.include("InvalidInternalKey");
+        expect(err.toString()).to.include("InternalKeyEmpty");
         console.log("✅ Correctly rejected empty internal_key");
       }
     });
@@ -286,4 +314,418 @@ describe("exchainge-program", () => {
       }
     });
   });
+
+  describe("commit_hash / reveal_registration (commit-reveal)", () => {
+    function computeCommitment(datasetHash: Buffer, salt: Buffer): Buffer {
+      return crypto.createHash('sha256').update(Buffer.concat([datasetHash, salt])).digest();
+    }
+
+    it("Reveals a committed hash and preserves the commit timestamp", async () => {
+      const registryKeypair = anchor.web3.Keypair.generate();
+      const datasetHash = crypto.randomBytes(32);
+      const salt = crypto.randomBytes(32);
+      const commitment = computeCommitment(datasetHash, salt);
+
+      await program.methods
+        .commitHash(Array.from(commitment))
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      const committed = await program.account.dataRegistry.fetch(
+        registryKeypair.publicKey
+      );
+      expect(Buffer.from(committed.commitment).toString('hex')).to.equal(
+        commitment.toString('hex')
+      );
+
+      await program.methods
+        .revealRegistration("reveal-test-key", Array.from(datasetHash), Array.from(salt))
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+        })
+        .rpc();
+
+      const revealed = await program.account.dataRegistry.fetch(
+        registryKeypair.publicKey
+      );
+
+      expect(revealed.internalKey).to.equal("reveal-test-key");
+      expect(Buffer.from(revealed.datasetHash).toString('hex')).to.equal(
+        datasetHash.toString('hex')
+      );
+      expect(revealed.commitment).to.be.null;
+      expect(revealed.createdAt.toNumber()).to.equal(committed.createdAt.toNumber());
+
+      console.log("✅ Commit-reveal registration successful");
+    });
+
+    it("Rejects reveal with wrong salt", async () => {
+      const registryKeypair = anchor.web3.Keypair.generate();
+      const datasetHash = crypto.randomBytes(32);
+      const commitment = computeCommitment(datasetHash, crypto.randomBytes(32));
+
+      await program.methods
+        .commitHash(Array.from(commitment))
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      try {
+        await program.methods
+          .revealRegistration("bad-salt-key", Array.from(datasetHash), Array.from(crypto.randomBytes(32)))
+          .accounts({
+            registry: registryKeypair.publicKey,
+            owner: owner.publicKey,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown error for mismatched commitment");
+      } catch (err) {
+        expect(err.toString()).to.include("CommitmentMismatch");
+        console.log("✅ Correctly rejected mismatched reveal");
+      }
+    });
+
+    it("Rejects update_hash while the commitment is pending", async () => {
+      const registryKeypair = anchor.web3.Keypair.generate();
+      const commitment = computeCommitment(crypto.randomBytes(32), crypto.randomBytes(32));
+
+      await program.methods
+        .commitHash(Array.from(commitment))
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      try {
+        await program.methods
+          .updateHash(Array.from(crypto.randomBytes(32)))
+          .accounts({
+            registry: registryKeypair.publicKey,
+            owner: owner.publicKey,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown error for unrevealed commitment");
+      } catch (err) {
+        expect(err.toString()).to.include("CommitmentNotRevealed");
+        console.log("✅ Correctly rejected update of pending commitment");
+      }
+    });
+
+    it("Rejects reveal on a registry without a commitment", async () => {
+      const registryKeypair = anchor.web3.Keypair.generate();
+      const datasetHash = crypto.randomBytes(32);
+
+      await program.methods
+        .registerHash("no-commitment-key", Array.from(datasetHash))
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      try {
+        await program.methods
+          .revealRegistration("no-commitment-key", Array.from(datasetHash), Array.from(crypto.randomBytes(32)))
+          .accounts({
+            registry: registryKeypair.publicKey,
+            owner: owner.publicKey,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown error for missing commitment");
+      } catch (err) {
+        expect(err.toString()).to.include("NoPendingCommitment");
+        console.log("✅ Correctly rejected reveal without commitment");
+      }
+    });
+  });
+
+  describe("validate_registration_params (dry run)", () => {
+    it("Returns validation result and derived hash without writing state", async () => {
+      const datasetId = 777;
+      const fileSize = 2048;
+      const fileKey = "dry-run-file-key";
+
+      const result = await program.methods
+        .validateRegistrationParams(new anchor.BN(datasetId), new anchor.BN(fileSize), fileKey)
+        .accounts({})
+        .view();
+
+      expect(result.isValid).to.be.true;
+      expect(result.fileKeyValid).to.be.true;
+      expect(result.fileSizeValid).to.be.true;
+      expect(Buffer.from(result.derivedHash).toString('hex')).to.equal(
+        computeHash(fileKey, datasetId, fileSize).toString('hex')
+      );
+
+      console.log("✅ Dry run returned expected hash");
+    });
+
+    it("Reports every failing check", async () => {
+      const result = await program.methods
+        .validateRegistrationParams(new anchor.BN(1), new anchor.BN(0), "")
+        .accounts({})
+        .view();
+
+      expect(result.isValid).to.be.false;
+      expect(result.fileKeyValid).to.be.false;
+      expect(result.fileSizeValid).to.be.false;
+      expect(result.derivedHash).to.be.null;
+
+      console.log("✅ Dry run flagged invalid params");
+    });
+  });
+
+  describe("get_registry_summary", () => {
+    it("Returns owner, hash, created_at and method", async () => {
+      const registryKeypair = anchor.web3.Keypair.generate();
+      const hash = crypto.randomBytes(32);
+
+      await program.methods
+        .registerHash("summary-test-key", Array.from(hash))
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      const summary = await program.methods
+        .getRegistrySummary()
+        .accounts({
+          registry: registryKeypair.publicKey,
+        })
+        .view();
+
+      const registryAccount = await program.account.dataRegistry.fetch(
+        registryKeypair.publicKey
+      );
+
+      expect(summary.owner.toString()).to.equal(owner.publicKey.toString());
+      expect(Buffer.from(summary.datasetHash).toString('hex')).to.equal(hash.toString('hex'));
+      expect(summary.createdAt.toNumber()).to.equal(registryAccount.createdAt.toNumber());
+      expect(summary.method).to.deep.equal({ precomputed: {} });
+      expect(summary.pending).to.be.false;
+
+      console.log("✅ Registry summary returned");
+    });
+
+    it("Flags unrevealed commitments as pending", async () => {
+      const registryKeypair = anchor.web3.Keypair.generate();
+      const commitment = crypto.createHash('sha256').update(crypto.randomBytes(64)).digest();
+
+      await program.methods
+        .commitHash(Array.from(commitment))
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      const summary = await program.methods
+        .getRegistrySummary()
+        .accounts({
+          registry: registryKeypair.publicKey,
+        })
+        .view();
+
+      expect(summary.pending).to.be.true;
+      expect(summary.method).to.deep.equal({ commitReveal: {} });
+
+      console.log("✅ Pending commitment flagged in summary");
+    });
+  });
+
+  describe("reattest_dataset", () => {
+    it("Recomputes hash, keeps previous hash and bumps revision", async () => {
+      const datasetId = 4242;
+      const registryKeypair = anchor.web3.Keypair.generate();
+      const originalHash = computeHash("reattest-v1", datasetId, 1000);
+
+      await program.methods
+        .registerDataset(new anchor.BN(datasetId), new anchor.BN(1000), "reattest-v1")
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      await program.methods
+        .reattestDataset(new anchor.BN(2000), "reattest-v2")
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+        })
+        .rpc();
+
+      const registryAccount = await program.account.dataRegistry.fetch(
+        registryKeypair.publicKey
+      );
+
+      expect(Buffer.from(registryAccount.datasetHash).toString('hex')).to.equal(
+        computeHash("reattest-v2", datasetId, 2000).toString('hex')
+      );
+      expect(Buffer.from(registryAccount.previousHash).toString('hex')).to.equal(
+        originalHash.toString('hex')
+      );
+      expect(registryAccount.revision).to.equal(1);
+      expect(registryAccount.fileSize.toNumber()).to.equal(2000);
+      expect(registryAccount.fileKey).to.equal("reattest-v2");
+
+      console.log("✅ Re-attestation successful");
+    });
+
+    it("Keeps the revision-1 hash as previous_hash on a second re-attestation", async () => {
+      const datasetId = 4343;
+      const registryKeypair = anchor.web3.Keypair.generate();
+
+      await program.methods
+        .registerDataset(new anchor.BN(datasetId), new anchor.BN(1000), "reattest-v1")
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      for (const [fileSize, fileKey] of [[2000, "reattest-v2"], [3000, "reattest-v3"]] as const) {
+        await program.methods
+          .reattestDataset(new anchor.BN(fileSize), fileKey)
+          .accounts({
+            registry: registryKeypair.publicKey,
+            owner: owner.publicKey,
+          })
+          .rpc();
+      }
+
+      const registryAccount = await program.account.dataRegistry.fetch(
+        registryKeypair.publicKey
+      );
+
+      expect(registryAccount.revision).to.equal(2);
+      expect(Buffer.from(registryAccount.datasetHash).toString('hex')).to.equal(
+        computeHash("reattest-v3", datasetId, 3000).toString('hex')
+      );
+      expect(Buffer.from(registryAccount.previousHash).toString('hex')).to.equal(
+        computeHash("reattest-v2", datasetId, 2000).toString('hex')
+      );
+
+      console.log("✅ Second re-attestation tracked revision-1 hash");
+    });
+
+    it("Rejects re-attestation by a non-owner", async () => {
+      const registryKeypair = anchor.web3.Keypair.generate();
+      const attacker = anchor.web3.Keypair.generate();
+
+      await program.methods
+        .registerDataset(new anchor.BN(4444), new anchor.BN(1000), "reattest-owner")
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      try {
+        await program.methods
+          .reattestDataset(new anchor.BN(2000), "reattest-attacker")
+          .accounts({
+            registry: registryKeypair.publicKey,
+            owner: attacker.publicKey,
+          })
+          .signers([attacker])
+          .rpc();
+
+        expect.fail("Should have thrown error for non-owner");
+      } catch (err) {
+        expect(err.toString()).to.include("Unauthorized");
+        console.log("✅ Correctly rejected non-owner re-attestation");
+      }
+    });
+
+    it("Rejects re-attesting a pre-computed registration", async () => {
+      const registryKeypair = anchor.web3.Keypair.generate();
+
+      await program.methods
+        .registerHash("reattest-precomputed", Array.from(crypto.randomBytes(32)))
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      try {
+        await program.methods
+          .reattestDataset(new anchor.BN(2000), "reattest-v2")
+          .accounts({
+            registry: registryKeypair.publicKey,
+            owner: owner.publicKey,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown error for non-trustless registry");
+      } catch (err) {
+        expect(err.toString()).to.include("NotTrustlessRegistry");
+        console.log("✅ Correctly rejected pre-computed registry");
+      }
+    });
+  });
+
+  describe("migrate_registry", () => {
+    it("Rejects migrating a registry already on the current layout", async () => {
+      const registryKeypair = anchor.web3.Keypair.generate();
+
+      await program.methods
+        .registerHash("migrate-test-key", Array.from(crypto.randomBytes(32)))
+        .accounts({
+          registry: registryKeypair.publicKey,
+          owner: owner.publicKey,
+          systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .signers([registryKeypair])
+        .rpc();
+
+      try {
+        await program.methods
+          .migrateRegistry()
+          .accounts({
+            registry: registryKeypair.publicKey,
+            owner: owner.publicKey,
+            systemProgram: anchor.web3.SystemProgram.programId,
+          })
+          .rpc();
+
+        expect.fail("Should have thrown error for current-layout registry");
+      } catch (err) {
+        expect(err.toString()).to.include("RegistryAlreadyMigrated");
+        console.log("✅ Correctly rejected migration of current-layout registry");
+      }
+    });
+  });
 });